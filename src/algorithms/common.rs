@@ -0,0 +1,132 @@
+use miette::{miette, Result};
+use rand::distributions::Uniform;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+
+use crate::core::problem::Bounds;
+
+/// The result of an [`crate::algorithms::optimizer::Optimizer`] run: the best
+/// position found and its objective value.
+#[derive(Debug, Clone)]
+pub struct Minimum {
+    pub value: f64,
+    pub position: Vec<f64>,
+}
+
+impl Minimum {
+    #[inline]
+    pub fn new(value: f64, position: Vec<f64>) -> Self {
+        Self { value, position }
+    }
+}
+
+/// A swarm's current best-known position and value, tracked while a run is
+/// still in progress (as opposed to [`Minimum`], which is the final result
+/// handed back to the caller once the run has finished).
+#[derive(Clone)]
+pub struct PointAndValue {
+    pub position: Vec<f64>,
+    pub value: f64,
+}
+
+impl PointAndValue {
+    #[inline]
+    pub fn new(position: Vec<f64>, value: f64) -> Self {
+        Self { position, value }
+    }
+}
+
+/// Whether a swarm's just-finished iteration found a new global best, used by
+/// [`run_until_stuck`] to track `stuck_run_iterations_count`.
+pub struct IterationResult {
+    pub new_global_minimum: bool,
+}
+
+impl IterationResult {
+    #[inline]
+    pub fn new(new_global_minimum: bool) -> Self {
+        Self { new_global_minimum }
+    }
+}
+
+/// Common interface for the per-algorithm swarm driver loop in
+/// [`run_until_stuck`]. Implemented by `firefly::FireflySwarm` and
+/// `pso::PSOSwarm` so both share the same iterations-since-improvement
+/// tracking and early-abort logic, rather than duplicating it (and its
+/// `Result`/`Minimum` construction) in each `perform_*_optimization` function.
+pub trait SwarmOptimization {
+    /// Advances the swarm by one iteration. `current_iteration` is passed
+    /// through even though not every algorithm uses it (e.g. firefly's
+    /// jitter decay does, PSO currently doesn't).
+    fn step(&mut self, current_iteration: usize) -> IterationResult;
+
+    /// Consumes the swarm, returning its best-known solution (if any were
+    /// ever evaluated).
+    fn into_best_solution(self) -> Option<PointAndValue>;
+}
+
+/// Drives `swarm` for up to `maximum_iterations` iterations via
+/// [`SwarmOptimization::step`], aborting early once
+/// `stuck_run_iterations_count` consecutive iterations produced no new
+/// global best, then returns the best solution found as a [`Minimum`].
+pub fn run_until_stuck<S: SwarmOptimization>(
+    mut swarm: S,
+    maximum_iterations: usize,
+    stuck_run_iterations_count: usize,
+) -> Result<Minimum> {
+    let mut iterations_since_improvement: usize = 0;
+
+    for current_iteration in 0..maximum_iterations {
+        let result = swarm.step(current_iteration);
+
+        // Track iterations since improvement. If it reaches
+        // `stuck_run_iterations_count`, we abort the run and return an early
+        // minimum so far.
+        if result.new_global_minimum {
+            iterations_since_improvement = 0;
+        } else {
+            iterations_since_improvement += 1;
+        }
+
+        if iterations_since_improvement >= stuck_run_iterations_count {
+            break;
+        }
+    }
+
+    let best_solution = swarm
+        .into_best_solution()
+        .ok_or_else(|| miette!("Invalid run: no best solution at all?!"))?;
+
+    Ok(Minimum::new(best_solution.value, best_solution.position))
+}
+
+/// A small wrapper around a seeded PRNG that samples uniformly inside a
+/// given [`Bounds`] range. Shared by every swarm-based optimizer in
+/// `algorithms` (firefly, PSO, ...) - used for in-bounds swarm
+/// initialization and for per-member randomization terms (movement jitter,
+/// velocity-update coefficients, ...) via `Bounds::new(0f64, 1f64)`.
+#[derive(Clone)]
+pub struct UniformRNG {
+    distribution: Uniform<f64>,
+    generator: Pcg64Mcg,
+}
+
+impl UniformRNG {
+    pub fn new(bounds: Bounds, seed: [u8; 16]) -> Self {
+        Self {
+            distribution: Uniform::new_inclusive(bounds.lower, bounds.upper),
+            generator: Pcg64Mcg::from_seed(seed),
+        }
+    }
+
+    /// Samples a single uniformly-distributed value inside the configured bounds.
+    #[inline]
+    pub fn sample(&mut self) -> f64 {
+        self.generator.sample(self.distribution)
+    }
+
+    /// Samples `count` uniformly-distributed values inside the configured bounds.
+    pub fn sample_multiple(&mut self, count: usize) -> Vec<f64> {
+        (0..count).map(|_| self.sample()).collect()
+    }
+}
@@ -0,0 +1,121 @@
+use std::time::Instant;
+
+use rand::distributions::Uniform;
+use rand::Rng;
+use rand_pcg::Pcg64Mcg;
+
+use super::individual_firefly::Firefly;
+use super::options::FireflyOptions;
+use super::progress::StepTiming;
+use crate::core::problem::BBOBProblem;
+
+/// Picks a random swarm index distinct from every index in `exclude`.
+/// Assumes `fireflies.len() > exclude.len()` - callers must uphold that (see
+/// the `fireflies.len() >= 4` check in `perform_de_step`), otherwise this
+/// spins forever looking for a candidate that doesn't exist.
+fn sample_distinct_index(
+    rng: &mut Pcg64Mcg,
+    population_size: usize,
+    exclude: &[usize],
+) -> usize {
+    let distribution = Uniform::new(0, population_size);
+
+    loop {
+        let candidate = rng.sample(distribution);
+        if !exclude.contains(&candidate) {
+            return candidate;
+        }
+    }
+}
+
+/// Updates the firefly at `member_index` with a single DE/rand/1/bin step
+/// (see [1]): picks three other distinct members `r1`, `r2`, `r3`, forms a
+/// mutant `v = x_r1 + F * (x_r2 - x_r3)` (clamped to the problem's bounds),
+/// performs per-dimension binomial crossover with the target `x_i` (always
+/// keeping at least one mutant dimension), and returns the trial only if it
+/// improves on `x_i`'s objective value - otherwise the original member is
+/// returned unchanged.
+///
+/// [1] https://doi.org/10.1023/A:1008202821328
+pub fn perform_de_step(
+    member_index: usize,
+    fireflies: &[Firefly],
+    problem: &BBOBProblem,
+    options: &FireflyOptions,
+    rng: &mut Pcg64Mcg,
+) -> (Firefly, StepTiming) {
+    // `r1`, `r2` and `r3` below must all be distinct from `member_index` and
+    // from each other, so the population needs at least 4 members. Nothing
+    // validates `FireflyOptions::swarm_size` before it gets here, so without
+    // this check a too-small (but otherwise legal) swarm combined with
+    // `de_member_count > 0` would make `sample_distinct_index` spin forever.
+    assert!(
+        fireflies.len() >= 4,
+        "DE/rand/1/bin requires a swarm of at least 4 to pick 3 distinct \
+         donors per member, but swarm_size is only {}",
+        fireflies.len()
+    );
+
+    let movement_start_time = Instant::now();
+
+    let target = &fireflies[member_index];
+    let dimensions = target.position.len();
+
+    let r1 = sample_distinct_index(rng, fireflies.len(), &[member_index]);
+    let r2 =
+        sample_distinct_index(rng, fireflies.len(), &[member_index, r1]);
+    let r3 = sample_distinct_index(
+        rng,
+        fireflies.len(),
+        &[member_index, r1, r2],
+    );
+
+    let bounds = problem.bounds();
+    let mutant: Vec<f64> = (0..dimensions)
+        .map(|dimension_index| {
+            let mutated_value = fireflies[r1].position[dimension_index]
+                + options.mutation_factor
+                    * (fireflies[r2].position[dimension_index]
+                        - fireflies[r3].position[dimension_index]);
+
+            mutated_value.clamp(bounds.lower, bounds.upper)
+        })
+        .collect();
+
+    // Binomial crossover: each dimension independently takes the mutant's value
+    // with probability `CR`, except for one forced dimension that always does
+    // (otherwise the trial could end up identical to the target).
+    let crossover_distribution = Uniform::new(0f64, 1f64);
+    let forced_mutant_dimension =
+        rng.sample(Uniform::new(0, dimensions));
+
+    let trial_position: Vec<f64> = (0..dimensions)
+        .map(|dimension_index| {
+            if dimension_index == forced_mutant_dimension
+                || rng.sample(crossover_distribution)
+                    < options.crossover_rate
+            {
+                mutant[dimension_index]
+            } else {
+                target.position[dimension_index]
+            }
+        })
+        .collect();
+
+    let movement_time = movement_start_time.elapsed();
+
+    let evaluation_start_time = Instant::now();
+    let trial_value = problem.evaluate(&trial_position);
+    let evaluation_time = evaluation_start_time.elapsed();
+
+    let mut new_member = target.clone();
+    if trial_value < target.objective_function_value {
+        new_member.position = trial_position;
+        new_member.objective_function_value = trial_value;
+    }
+
+    (
+        new_member,
+        StepTiming::new(movement_time, evaluation_time),
+    )
+}
@@ -0,0 +1,111 @@
+use std::time::Instant;
+
+use super::options::FireflyOptions;
+use super::progress::StepTiming;
+use crate::algorithms::common::UniformRNG;
+use crate::core::problem::BBOBProblem;
+
+/// A single firefly in the swarm, carrying its own position, cached objective
+/// value and a dedicated (pre-seeded) RNG for movement jitter, so runs stay
+/// deterministic even when the swarm is processed across multiple threads.
+///
+/// `BBOBProblem::evaluate` only reads the problem definition, so fireflies can
+/// be updated concurrently against a single, shared `&BBOBProblem`.
+#[derive(Clone)]
+pub struct Firefly {
+    /// RNG used for the "randomization" term of the movement rule.
+    /// Sampled from `Bounds::new(0f64, 1f64)`.
+    zero_to_one_rng: UniformRNG,
+
+    pub position: Vec<f64>,
+
+    pub objective_function_value: f64,
+}
+
+impl Firefly {
+    pub fn new(
+        zero_to_one_rng: UniformRNG,
+        position: Vec<f64>,
+        problem: &BBOBProblem,
+    ) -> Self {
+        let objective_function_value = problem.evaluate(&position);
+
+        Self {
+            zero_to_one_rng,
+            position,
+            objective_function_value,
+        }
+    }
+
+    /// Moves this firefly towards a `brighter` (more fit) firefly, following
+    /// the firefly algorithm's attraction rule (see [1]):
+    /// `β(r) = β_0 * exp(-γ * r^2)`, where `r` is the Euclidean distance
+    /// between the two fireflies, plus a randomization term whose magnitude
+    /// cools down geometrically as `current_iteration` grows (see
+    /// `FireflyOptions::jitter_decay`).
+    ///
+    /// Returns a [`StepTiming`] breakdown of the time spent on the movement
+    /// math versus the objective-function evaluation, used by
+    /// `FireflyOptions::progress_reporting_enabled` to report per-worker stats.
+    ///
+    /// [1] https://arxiv.org/abs/1308.3898
+    pub fn move_towards(
+        &mut self,
+        brighter: &Firefly,
+        problem: &BBOBProblem,
+        options: &FireflyOptions,
+        current_iteration: usize,
+    ) -> StepTiming {
+        let movement_start_time = Instant::now();
+
+        let distance_squared: f64 = self
+            .position
+            .iter()
+            .zip(brighter.position.iter())
+            .map(|(self_coordinate, brighter_coordinate)| {
+                (brighter_coordinate - self_coordinate).powi(2)
+            })
+            .sum();
+
+        let attractiveness = options.attractiveness_coefficient
+            * (-options.light_absorption_coefficient * distance_squared).exp();
+
+        let decayed_jitter_coefficient = options.movement_jitter_coefficient
+            * options.jitter_decay.powi(current_iteration as i32);
+
+        let bounds = problem.bounds();
+
+        let new_position: Vec<f64> = self
+            .position
+            .iter()
+            .zip(brighter.position.iter())
+            .map(|(self_coordinate, brighter_coordinate)| {
+                let jitter = decayed_jitter_coefficient
+                    * (self.zero_to_one_rng.sample() - 0.5f64);
+
+                let moved_coordinate = self_coordinate
+                    + attractiveness * (brighter_coordinate - self_coordinate)
+                    + jitter;
+
+                moved_coordinate.clamp(bounds.lower, bounds.upper)
+            })
+            .collect();
+
+        let movement_time = movement_start_time.elapsed();
+
+        let evaluation_start_time = Instant::now();
+        self.objective_function_value = problem.evaluate(&new_position);
+        let evaluation_time = evaluation_start_time.elapsed();
+
+        self.position = new_position;
+
+        StepTiming::new(movement_time, evaluation_time)
+    }
+
+    /// Replaces this firefly's position outright (e.g. when the event-horizon
+    /// operator recycles a collapsed firefly) and re-evaluates its objective value.
+    pub fn reinitialize_at(&mut self, position: Vec<f64>, problem: &BBOBProblem) {
+        self.objective_function_value = problem.evaluate(&position);
+        self.position = position;
+    }
+}
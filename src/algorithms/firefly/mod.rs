@@ -1,46 +1,33 @@
+use differential_evolution::perform_de_step;
 use individual_firefly::Firefly;
-use miette::{miette, Result};
-use options::FireflyOptions;
+use miette::Result;
+pub use options::{FireflyOptions, InitializationStrategy};
+use progress::{ProgressReporter, StepTiming};
 use rand::distributions::Uniform;
 use rand::{Rng, SeedableRng};
 use rand_pcg::Pcg64Mcg;
 use rayon::prelude::*;
-use rng::UniformRNG;
 
-use super::common::Minimum;
+use super::common::{
+    run_until_stuck,
+    IterationResult,
+    Minimum,
+    PointAndValue,
+    SwarmOptimization,
+    UniformRNG,
+};
+use super::optimizer::Optimizer;
 use crate::core::problem::{BBOBProblem, Bounds};
 
+mod differential_evolution;
 mod individual_firefly;
 mod options;
-mod rng;
+mod progress;
+mod sobol;
 
 // TODO Notes: we could merge the firefly algorithm with the multi-swarm optimization strategy (multiple independent swarms)
 //      See https://en.wikipedia.org/wiki/Multi-swarm_optimization
 
-#[derive(Clone)]
-pub struct PointAndValue {
-    pub position: Vec<f64>,
-    pub value: f64,
-}
-
-impl PointAndValue {
-    #[inline]
-    pub fn new(position: Vec<f64>, value: f64) -> Self {
-        Self { position, value }
-    }
-}
-
-pub struct IterationResult {
-    pub new_global_minimum: bool,
-}
-
-impl IterationResult {
-    #[inline]
-    pub fn new(new_global_minimum: bool) -> Self {
-        Self { new_global_minimum }
-    }
-}
-
 
 /// Entire firefly swarm.
 pub struct FireflySwarm<'problem, 'options> {
@@ -52,30 +39,68 @@ pub struct FireflySwarm<'problem, 'options> {
 
     // Vector of fireflies - this is the swarm.
     fireflies: Vec<Firefly>,
+
+    // Drives DE member selection (`r1`, `r2`, `r3`) and crossover when
+    // `options.de_member_count > 0`. Always seeded, but never drawn from in
+    // plain-FA runs.
+    de_rng: Pcg64Mcg,
+
+    // Generates uniformly-distributed in-bounds positions. Used for `Uniform`
+    // swarm initialization and reused by the event-horizon operator (see
+    // `options.event_horizon_enabled`) to reinitialize collapsed fireflies.
+    in_bounds_generator: UniformRNG,
+
+    // Present when `options.progress_reporting_enabled` is set; prints live
+    // progress after every iteration.
+    progress_reporter: Option<ProgressReporter>,
 }
 
 impl<'problem, 'options> FireflySwarm<'problem, 'options> {
     pub fn initialize(
-        mut problem: BBOBProblem<'problem>,
+        problem: BBOBProblem<'problem>,
         options: &'options FireflyOptions,
     ) -> Self {
         // Initialize the swarm.
         let input_dimensions = problem.input_dimensions;
 
         // Generates uniformly-distributed f64 values in the problem's range (-5 to 5).
-        let mut in_bounds_uniform_generator = UniformRNG::new(
+        // Kept around (rather than being a local variable) so the event-horizon
+        // operator can later reuse it to reinitialize collapsed fireflies.
+        let mut in_bounds_generator = UniformRNG::new(
             problem.bounds(),
             options.in_bounds_random_generator_seed,
         );
 
+        // Generates the swarm's starting positions, per `options.initialization_strategy`.
+        let initial_positions: Vec<Vec<f64>> = match options.initialization_strategy
+        {
+            InitializationStrategy::Uniform => (0..options.swarm_size)
+                .map(|_| in_bounds_generator.sample_multiple(input_dimensions))
+                .collect(),
+            InitializationStrategy::Sobol => {
+                // The first `swarm_size` points of a scrambled quasi-Sobol
+                // sequence, scaled into the problem's bounds - gives distinct,
+                // decorrelated per-axis coverage rather than pure uniform
+                // sampling (see `firefly::sobol` for why we stop short of
+                // claiming true low-discrepancy guarantees).
+                sobol::scrambled_sobol_sequence(
+                    input_dimensions,
+                    options.swarm_size,
+                    problem.bounds(),
+                    options.in_bounds_random_generator_seed,
+                )
+            }
+        };
+
         // Temporary reseeding RNG - generates u8 seeds for individual fireflies' RNGs.
         // This way we can preserve determinism, even when multi-threading.
         let u8_uniform_distribution = Uniform::new_inclusive(u8::MIN, u8::MAX);
         let mut firefly_seed_generator =
             Pcg64Mcg::from_seed(options.firefly_seed_generator_seed);
 
-        let mut fireflies: Vec<Firefly> = (0..options.swarm_size)
-            .map(|_| {
+        let mut fireflies: Vec<Firefly> = initial_positions
+            .into_iter()
+            .map(|initial_position| {
                 let further_generation_seed: [u8; 16] = (0..16)
                     .map(|_| {
                         firefly_seed_generator.sample(u8_uniform_distribution)
@@ -84,16 +109,13 @@ impl<'problem, 'options> FireflySwarm<'problem, 'options> {
                     .try_into()
                     .expect("BUG: Iterator did not generate 16 u8?!?!");
 
-                let initial_position: Vec<f64> = in_bounds_uniform_generator
-                    .sample_multiple(input_dimensions);
-
                 Firefly::new(
                     UniformRNG::new(
                         Bounds::new(0f64, 1f64),
                         further_generation_seed,
                     ),
                     initial_position,
-                    &mut problem,
+                    &problem,
                 )
             })
             .collect();
@@ -104,11 +126,20 @@ impl<'problem, 'options> FireflySwarm<'problem, 'options> {
                 .total_cmp(&first.objective_function_value)
         });
 
+        let de_rng = Pcg64Mcg::from_seed(options.de_random_generator_seed);
+
+        let progress_reporter = options
+            .progress_reporting_enabled
+            .then(|| ProgressReporter::new(options.maximum_iterations));
+
         Self {
             problem,
             best_solution: None,
             options,
             fireflies,
+            de_rng,
+            in_bounds_generator,
+            progress_reporter,
         }
     }
 
@@ -127,37 +158,82 @@ impl<'problem, 'options> FireflySwarm<'problem, 'options> {
         self.best_solution = Some(PointAndValue::new(position, value));
     }
 
-    pub fn perform_iteration(&mut self) -> IterationResult {
+    pub fn perform_iteration(
+        &mut self,
+        current_iteration: usize,
+    ) -> IterationResult {
         assert_eq!(self.fireflies.len(), self.options.swarm_size);
 
-        let mut result = IterationResult::new(false);
+        // In hybrid mode, the first `de_member_count` fireflies (the worst-performing
+        // ones, as the swarm is kept sorted worst-to-best) are updated via a
+        // differential-evolution step instead of the usual attraction rule. This part
+        // stays single-threaded, since it draws from a single shared `de_rng` stream.
+        let de_member_count =
+            self.options.de_member_count.min(self.fireflies.len());
 
         let mut new_firefly_swarm: Vec<Firefly> =
             Vec::with_capacity(self.fireflies.len());
+        let mut worker_timings: Vec<(usize, StepTiming)> =
+            Vec::with_capacity(self.fireflies.len());
 
-        for main_firefly_index in 0..self.fireflies.len() {
-            let mut new_main_firefly =
-                self.fireflies[main_firefly_index].clone();
-
-            // For each firefly `F` in the swarm, compare it with each other firefly `C`.
-            // If `C` is lighter (i.e. more fit, smaller objective value (we're minimizing)),
-            // then `F` moves towards `C` (with some light falloff and other factors).
-            // Optimization: as we'd sorted the array previously, we skip all the worse fireflies.
-            for brighter_firefly in
-                self.fireflies.iter().skip(main_firefly_index + 1)
-            {
-                if brighter_firefly.objective_function_value
-                    < new_main_firefly.objective_function_value
+        for member_index in 0..de_member_count {
+            let (new_member, timing) = perform_de_step(
+                member_index,
+                &self.fireflies,
+                &self.problem,
+                self.options,
+                &mut self.de_rng,
+            );
+
+            new_firefly_swarm.push(new_member);
+            worker_timings.push((0, timing));
+        }
+
+        // The rest of the swarm moves via the usual attraction rule. Each firefly's
+        // new position only depends on the (read-only) previous iteration's swarm and
+        // its own pre-seeded RNG, so this is computed in parallel; only the subsequent
+        // sort and best-value update are single-threaded.
+        let fa_results: Vec<(Firefly, usize, StepTiming)> = (de_member_count
+            ..self.fireflies.len())
+            .into_par_iter()
+            .map(|main_firefly_index| {
+                let mut new_main_firefly =
+                    self.fireflies[main_firefly_index].clone();
+                let mut timing = StepTiming::default();
+
+                // For each firefly `F` in the swarm, compare it with each other firefly `C`.
+                // If `C` is lighter (i.e. more fit, smaller objective value (we're minimizing)),
+                // then `F` moves towards `C` (with some light falloff and other factors).
+                // Optimization: as we'd sorted the array previously, we skip all the worse fireflies.
+                for brighter_firefly in
+                    self.fireflies.iter().skip(main_firefly_index + 1)
                 {
-                    new_main_firefly.move_towards(
-                        brighter_firefly,
-                        &mut self.problem,
-                        self.options,
-                    );
+                    if brighter_firefly.objective_function_value
+                        < new_main_firefly.objective_function_value
+                    {
+                        let step_timing = new_main_firefly.move_towards(
+                            brighter_firefly,
+                            &self.problem,
+                            self.options,
+                            current_iteration,
+                        );
+
+                        timing.movement += step_timing.movement;
+                        timing.evaluation += step_timing.evaluation;
+                    }
                 }
-            }
 
-            // Update minimum value if improved.
+                // Read inside the parallel closure, so it names the rayon
+                // worker that actually did this firefly's work.
+                let worker_index = rayon::current_thread_index().unwrap_or(0);
+
+                (new_main_firefly, worker_index, timing)
+            })
+            .collect();
+
+        let mut result = IterationResult::new(false);
+
+        for (new_main_firefly, worker_index, timing) in fa_results {
             if self.is_better_than_minimum(
                 new_main_firefly.objective_function_value,
             ) {
@@ -169,6 +245,7 @@ impl<'problem, 'options> FireflySwarm<'problem, 'options> {
                 result.new_global_minimum = true;
             }
 
+            worker_timings.push((worker_index, timing));
             new_firefly_swarm.push(new_main_firefly);
         }
 
@@ -182,8 +259,122 @@ impl<'problem, 'options> FireflySwarm<'problem, 'options> {
         assert_eq!(new_firefly_swarm.len(), self.options.swarm_size);
         self.fireflies = new_firefly_swarm;
 
+        if self.options.event_horizon_enabled && self.apply_event_horizon() {
+            // Reinitializing a collapsed firefly gives it an unrelated random
+            // position/value, which can violate the worst-to-best sort order
+            // the rest of the algorithm depends on (the attraction loop above
+            // only scans `.skip(main_firefly_index + 1)` for brighter
+            // candidates, and the DE hybrid in `#chunk0-1` assumes the first
+            // `de_member_count` entries are the worst performers). Re-sort so
+            // the next iteration sees a consistent swarm again.
+            self.fireflies.sort_unstable_by(|first, second| {
+                second
+                    .objective_function_value
+                    .total_cmp(&first.objective_function_value)
+            });
+        }
+
+        if let Some(progress_reporter) = &self.progress_reporter {
+            let best_value = self
+                .best_solution
+                .as_ref()
+                .map(|solution| solution.value)
+                .unwrap_or(f64::INFINITY);
+
+            progress_reporter.report(
+                current_iteration,
+                best_value,
+                &worker_timings,
+            );
+        }
+
         result
     }
+
+    /// Black Hole event-horizon diversity operator: treats the current
+    /// `best_solution` as a black hole with event-horizon radius
+    /// `R = f_best / sum(f_i)` over the swarm's objective values. Any firefly
+    /// whose Euclidean distance to the best position falls below `R` has
+    /// collapsed onto the incumbent and is reinitialized to a fresh
+    /// uniformly-random in-bounds position, recycling it for further
+    /// exploration. Returns whether any firefly was actually reinitialized,
+    /// so the caller knows whether the swarm's sort order needs repairing.
+    ///
+    /// `R`'s formula assumes every `f_i` (and `f_best`) is positive, per the
+    /// operator's originally-published gravitational-search formulation.
+    /// BBOB/COCO functions commonly have negative objective values (shifted
+    /// optima), and more of the swarm's values go negative as it converges
+    /// near such optima - so we can't apply the formula to `f_i` directly.
+    /// Instead we shift every value relative to the swarm's worst (largest)
+    /// objective value before computing `R`, i.e. `R = (worst - f_best) /
+    /// sum(worst - f_i)`: `worst - f_i` is always `>= 0` regardless of the
+    /// objective function's sign or offset, and is `0` exactly for the worst
+    /// firefly itself, so the formula stays well-defined on every BBOB
+    /// function instead of silently becoming a no-op on the ones with
+    /// negative-valued optima.
+    fn apply_event_horizon(&mut self) -> bool {
+        let Some(best_solution) = self.best_solution.clone() else {
+            return false;
+        };
+
+        let worst_value = self
+            .fireflies
+            .iter()
+            .map(|firefly| firefly.objective_function_value)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let shifted_best_value = worst_value - best_solution.value;
+        let shifted_value_sum: f64 = self
+            .fireflies
+            .iter()
+            .map(|firefly| worst_value - firefly.objective_function_value)
+            .sum();
+
+        if shifted_value_sum <= 0f64 {
+            // Every firefly (including the best) shares the same objective
+            // value - there's no diversity to restore.
+            return false;
+        }
+
+        let event_horizon_radius = shifted_best_value / shifted_value_sum;
+
+        let mut reinitialized_any = false;
+
+        for firefly in self.fireflies.iter_mut() {
+            let distance_to_best: f64 = firefly
+                .position
+                .iter()
+                .zip(best_solution.position.iter())
+                .map(|(coordinate, best_coordinate)| {
+                    (coordinate - best_coordinate).powi(2)
+                })
+                .sum::<f64>()
+                .sqrt();
+
+            if distance_to_best < event_horizon_radius {
+                let fresh_position = self
+                    .in_bounds_generator
+                    .sample_multiple(firefly.position.len());
+
+                firefly.reinitialize_at(fresh_position, &self.problem);
+                reinitialized_any = true;
+            }
+        }
+
+        reinitialized_any
+    }
+}
+
+impl SwarmOptimization for FireflySwarm<'_, '_> {
+    #[inline]
+    fn step(&mut self, current_iteration: usize) -> IterationResult {
+        self.perform_iteration(current_iteration)
+    }
+
+    #[inline]
+    fn into_best_solution(self) -> Option<PointAndValue> {
+        self.best_solution
+    }
 }
 
 
@@ -192,34 +383,27 @@ pub fn perform_firefly_swarm_optimization(
     options: Option<FireflyOptions>,
 ) -> Result<Minimum> {
     let options = options.unwrap_or_default();
+    let swarm = FireflySwarm::initialize(problem, &options);
 
-    // Initialize swarm
-    let mut swarm = FireflySwarm::initialize(problem, &options);
-    let mut iterations_since_improvement: usize = 0;
+    run_until_stuck(
+        swarm,
+        options.maximum_iterations,
+        options.stuck_run_iterations_count,
+    )
+}
 
-    // Perform up to `maximum_iterations` iterations.
-    for _ in 0..options.maximum_iterations {
-        let result = swarm.perform_iteration();
 
-        // Track iterations since improvement. If it reaches `stuck_run_iterations_count`,
-        // we abort the run an return an early minimum so far.
-        if result.new_global_minimum {
-            iterations_since_improvement = 0;
-        } else {
-            iterations_since_improvement += 1;
-        }
+/// [`Optimizer`] adapter for the firefly algorithm, so it can be benchmarked
+/// through the same harness as other algorithms (e.g. `pso`).
+pub struct FireflyOptimizer;
 
-        if iterations_since_improvement >= options.stuck_run_iterations_count {
-            break;
-        }
-    }
-
-    let best_solution = swarm
-        .best_solution
-        .ok_or_else(|| miette!("Invalid run: no best solution at all?!"))?;
+impl Optimizer for FireflyOptimizer {
+    type Options = FireflyOptions;
 
-    Ok(Minimum::new(
-        best_solution.value,
-        best_solution.position,
-    ))
+    fn optimize(
+        problem: BBOBProblem,
+        options: Option<FireflyOptions>,
+    ) -> Result<Minimum> {
+        perform_firefly_swarm_optimization(problem, options)
+    }
 }
@@ -1,3 +1,22 @@
+/// How the initial swarm positions are generated in `FireflySwarm::initialize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InitializationStrategy {
+    /// Sample each firefly's starting position independently via `UniformRNG`.
+    /// Simple, but leaves large empty gaps and clusters in higher dimensions.
+    #[default]
+    Uniform,
+
+    /// Seed the swarm with the first `swarm_size` points of a scrambled
+    /// quasi-Sobol sequence, scaled into the problem's `Bounds`. Each axis gets
+    /// its own distinct direction-number-derived sequence (see
+    /// `firefly::sobol`), which spreads the swarm out more evenly than
+    /// independent uniform sampling - though since our direction numbers
+    /// aren't the validated Joe & Kuo initial numbers, we don't claim true
+    /// low-discrepancy guarantees here, just distinct, decorrelated per-axis
+    /// coverage.
+    Sobol,
+}
+
 /// References:
 ///  [1] https://arxiv.org/abs/1308.3898
 #[derive(Debug, Clone)]
@@ -6,11 +25,18 @@ pub struct FireflyOptions {
     /// According to [1], the optimal swarm size is between 15 to 100 (or 25 to 40).
     pub swarm_size: usize,
 
-    /// A 16-byte random generator seed for the swarm initialization.
+    /// How the initial swarm positions are generated. See [`InitializationStrategy`].
+    pub initialization_strategy: InitializationStrategy,
+
+    /// A 16-byte random generator seed for the swarm initialization. Used both as the
+    /// `UniformRNG` seed under [`InitializationStrategy::Uniform`] and as the scrambling
+    /// seed under [`InitializationStrategy::Sobol`].
     pub in_bounds_random_generator_seed: [u8; 16],
 
-    /// A 16-byte random generator seed for movement jitter.
-    pub zero_to_one_random_generator_seed: [u8; 16],
+    /// A 16-byte random generator seed for the temporary RNG that hands out
+    /// each firefly's own movement-jitter seed in `FireflySwarm::initialize`
+    /// (see `Firefly::zero_to_one_rng`).
+    pub firefly_seed_generator_seed: [u8; 16],
 
     /// Maximum of iterations to perform.
     pub maximum_iterations: usize,
@@ -31,8 +57,58 @@ pub struct FireflyOptions {
 
     /// To prevent getting stuck in local minimums, we add some jitter to firefly movements,
     /// this coefficient controls how much. The value is generally around `0.01 * problemSize`.
-    // TODO Add simulated-annealing-like behaviour, see [1], page 2: 2.2 Parameter settings.
+    /// This is the initial value `α_0`; it decays over time, see `jitter_decay`.
     pub movement_jitter_coefficient: f64,
+
+    /// Geometric decay rate `θ` for the movement jitter, applied as
+    /// `α_t = α_0 * θ^t` (`t` being the current iteration), per [1], page 2:
+    /// 2.2 Parameter settings. Generally in `(0, 1)`; values close to `1`
+    /// barely cool the jitter, values close to `0` cool it almost immediately.
+    /// This gives the swarm simulated-annealing-like behaviour: wide exploration
+    /// early on, precise convergence later.
+    pub jitter_decay: f64,
+
+    /// How many of the `swarm_size` fireflies are, instead of moving via the usual
+    /// attraction rule, updated with a differential-evolution (DE) step each iteration
+    /// (see [2]). Set to `0` (the default) to disable the hybrid FA/DE behaviour entirely,
+    /// in which case `mutation_factor` and `crossover_rate` are unused.
+    ///
+    /// This tends to help the swarm escape the flat plateaus that pure FA can get stuck
+    /// on (see the `21.1` plateaus in the tuning comments in `Default` below).
+    ///
+    /// [2] https://doi.org/10.1023/A:1008202821328
+    pub de_member_count: usize,
+
+    /// DE mutation factor (`F`), scaling the differential `x_r2 - x_r3` when forming
+    /// a mutant vector. Generally in range `[0, 2]`.
+    pub mutation_factor: f64,
+
+    /// DE crossover rate (`CR`), the per-dimension probability of taking the mutant's
+    /// value (rather than the target vector's) during binomial crossover. Generally
+    /// in range `[0, 1]`.
+    pub crossover_rate: f64,
+
+    /// A 16-byte random generator seed used to drive the DE member selection
+    /// (`r1`, `r2`, `r3`) and binomial crossover, kept separate so enabling the
+    /// hybrid behaviour doesn't perturb the plain-FA random streams.
+    pub de_random_generator_seed: [u8; 16],
+
+    /// Enables the Black Hole event-horizon diversity operator (see [3]): at the
+    /// end of each iteration, the current best solution acts as a black hole with
+    /// an event-horizon radius `R = (f_worst - f_best) / sum(f_worst - f_i)`, i.e.
+    /// `f_best / sum(f_i)` computed on objective values shifted by the swarm's worst
+    /// value `f_worst` rather than on the raw values; any firefly that has
+    /// collapsed within `R` of the best position is reinitialized to a fresh
+    /// uniformly-random in-bounds position. Disabled by default.
+    ///
+    /// [3] https://doi.org/10.1016/j.ins.2012.08.023
+    pub event_horizon_enabled: bool,
+
+    /// Enables live progress reporting (iteration count/percentage, elapsed and
+    /// estimated-remaining time, current best value, and a per-worker movement-
+    /// vs-evaluation timing breakdown) after every iteration. Disabled by default
+    /// so benchmark timing (e.g. `cmd_run_firefly_optimization`) stays clean.
+    pub progress_reporting_enabled: bool,
 }
 
 impl Default for FireflyOptions {
@@ -48,11 +124,12 @@ impl Default for FireflyOptions {
             // MIN Values: swarm_size=150, iter=1000, light_absorption=0.01, jitter=0.001 -> 21.100002
             // MIN Values: swarm_size=150, iter=1000, light_absorption=0.001, jitter=0.001 -> 21.100002
             swarm_size: 150,
+            initialization_strategy: InitializationStrategy::Uniform,
             in_bounds_random_generator_seed: [
                 199, 248, 17, 170, 248, 248, 15, 82, 75, 207, 232, 76, 38, 70,
                 37, 111,
             ],
-            zero_to_one_random_generator_seed: [
+            firefly_seed_generator_seed: [
                 160, 142, 67, 136, 64, 230, 125, 10, 243, 246, 140, 229, 12, 95,
                 173, 104,
             ],
@@ -61,6 +138,17 @@ impl Default for FireflyOptions {
             attractiveness_coefficient: 0.8f64,
             light_absorption_coefficient: 0.025,
             movement_jitter_coefficient: 0.1,
+            jitter_decay: 0.97,
+            // Disabled by default, preserving the tuning results above.
+            de_member_count: 0,
+            mutation_factor: 0.8,
+            crossover_rate: 0.9,
+            de_random_generator_seed: [
+                74, 19, 201, 5, 88, 233, 9, 150, 61, 47, 210, 3, 128, 199, 52,
+                17,
+            ],
+            event_horizon_enabled: false,
+            progress_reporting_enabled: false,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// Time spent on a single firefly update, split into the "movement" portion
+/// (attraction/jitter/DE math) and the "evaluation" portion (the objective
+/// function call itself).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StepTiming {
+    pub movement: Duration,
+    pub evaluation: Duration,
+}
+
+impl StepTiming {
+    pub fn new(movement: Duration, evaluation: Duration) -> Self {
+        Self {
+            movement,
+            evaluation,
+        }
+    }
+
+    fn accumulate(&mut self, other: StepTiming) {
+        self.movement += other.movement;
+        self.evaluation += other.evaluation;
+    }
+}
+
+/// Prints live progress for a firefly optimization run: overall iteration
+/// count and percentage, elapsed and estimated-remaining time, the current
+/// best objective value, and a per-worker breakdown of time spent on firefly
+/// movement versus objective-function evaluation.
+///
+/// Disabled by default (see `FireflyOptions::progress_reporting_enabled`) so
+/// benchmark timing (e.g. in `cmd_run_firefly_optimization`) stays clean.
+pub struct ProgressReporter {
+    total_iterations: usize,
+    start_time: Instant,
+}
+
+impl ProgressReporter {
+    pub fn new(total_iterations: usize) -> Self {
+        Self {
+            total_iterations,
+            start_time: Instant::now(),
+        }
+    }
+
+    /// Aggregates `(worker_index, timing)` pairs collected from one parallel
+    /// iteration and prints a progress report.
+    pub fn report(
+        &self,
+        current_iteration: usize,
+        best_value: f64,
+        worker_timings: &[(usize, StepTiming)],
+    ) {
+        let mut per_worker: BTreeMap<usize, StepTiming> = BTreeMap::new();
+        for (worker_index, timing) in worker_timings {
+            per_worker.entry(*worker_index).or_default().accumulate(*timing);
+        }
+
+        let elapsed = self.start_time.elapsed();
+        let completed_iterations = current_iteration + 1;
+        let percent_complete =
+            100f64 * completed_iterations as f64 / self.total_iterations as f64;
+
+        let average_iteration_time =
+            elapsed.as_secs_f64() / completed_iterations as f64;
+        let remaining_iterations =
+            self.total_iterations.saturating_sub(completed_iterations);
+        let estimated_remaining =
+            Duration::from_secs_f64(average_iteration_time * remaining_iterations as f64);
+
+        println!(
+            "  iteration {:>6}/{} ({:>5.1}%)  elapsed {:>7.2}s  eta {:>7.2}s  best {:.6}",
+            completed_iterations,
+            self.total_iterations,
+            percent_complete,
+            elapsed.as_secs_f64(),
+            estimated_remaining.as_secs_f64(),
+            best_value,
+        );
+
+        for (worker_index, timing) in per_worker {
+            println!(
+                "    worker {:>2}: movement {:>8.3}ms  evaluation {:>8.3}ms",
+                worker_index,
+                timing.movement.as_secs_f64() * 1000f64,
+                timing.evaluation.as_secs_f64() * 1000f64,
+            );
+        }
+    }
+}
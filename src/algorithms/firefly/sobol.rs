@@ -0,0 +1,274 @@
+use rand::distributions::Uniform;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+
+use crate::core::problem::Bounds;
+
+/// Number of bits (and therefore the maximum sequence length, `2^BITS`) each
+/// direction number is generated with. 30 bits comfortably covers the swarm
+/// sizes used by this crate (tens to a few hundred fireflies).
+const BITS: u32 = 30;
+
+/// Primitive polynomials (in GF(2), packed with every coefficient as one bit,
+/// leading and constant term included, e.g. `11 = 0b1011 = x^3 + x + 1`) used
+/// to generate the direction numbers for dimensions 2 through 41, following
+/// Joe & Kuo (2008) [1]. Dimension 1 is handled separately below (it
+/// degenerates to the plain van der Corput sequence in base 2).
+///
+/// [1] https://web.maths.unsw.edu.au/~fkuo/sobol/
+const PRIMITIVE_POLYNOMIALS: [u32; 40] = [
+    3, 7, 11, 13, 19, 25, 37, 59, 47, 61, 55, 41, 67, 97, 91, 109, 103,
+    115, 131, 193, 137, 145, 143, 241, 157, 185, 167, 229, 171, 213, 191,
+    253, 203, 211, 239, 247, 285, 369, 299, 301,
+];
+
+/// Returns the degree of a primitive polynomial packed the way
+/// [`PRIMITIVE_POLYNOMIALS`] is (i.e. the 0-based position of its highest set bit).
+fn polynomial_degree(polynomial: u32) -> u32 {
+    31 - polynomial.leading_zeros()
+}
+
+/// Derives a valid (odd, `< 2^i`) initial direction number `m_i` for a given
+/// dimension and index.
+///
+/// The previous version of this (`(2*dimension + i) % 2^i | 1`) collapsed to
+/// `0` (then `| 1` -> `1`) for every other dimension once `i >= 2`, silently
+/// duplicating the van der Corput sequence (dimension 0) across several axes -
+/// caught in review via a pairwise-correlation check. Mixing dimension and
+/// index together before reducing makes that kind of recurring, predictable
+/// collision far less likely, though `build_direction_numbers` still checks
+/// for (and breaks) any duplicate table that slips through regardless.
+fn initial_direction_number(dimension: usize, i: u32) -> u32 {
+    let mixed = (dimension as u32)
+        .wrapping_mul(2 * i + 1)
+        .wrapping_add(i)
+        .wrapping_mul(2_654_435_761); // Knuth's multiplicative hash constant
+
+    (mixed % (1 << i)) | 1
+}
+
+/// Generates the first `count` points of a quasi-Sobol sequence in
+/// `dimensions` dimensions, then applies a random digital (Cranley-Patterson)
+/// shift per dimension so repeated calls with different seeds don't always
+/// produce the exact same point set - this is what "scrambled" refers to here.
+///
+/// Note: since `direction_numbers_for_dimension` derives its initial direction
+/// numbers instead of using the validated Joe & Kuo tables (see there), this
+/// only guarantees each axis gets its own distinct, decorrelated sequence, not
+/// a true low-discrepancy point set.
+pub fn scrambled_sobol_sequence(
+    dimensions: usize,
+    count: usize,
+    bounds: Bounds,
+    scramble_seed: [u8; 16],
+) -> Vec<Vec<f64>> {
+    let direction_numbers = build_direction_numbers(dimensions);
+
+    // Raw (unshifted, unscaled) Sobol points in `[0, 1)^dimensions`.
+    let mut points: Vec<Vec<u32>> =
+        Vec::with_capacity(count);
+    let mut current = vec![0u32; dimensions];
+    points.push(current.clone());
+
+    for index in 1..count {
+        // Gray-code construction: flipping the `c`-th bit of `index - 1` (the
+        // index of the rightmost zero bit of `index - 1`) advances the sequence.
+        let rightmost_zero_bit = (index as u32 - 1).trailing_ones();
+
+        for dimension in 0..dimensions {
+            current[dimension] ^=
+                direction_numbers[dimension][rightmost_zero_bit as usize];
+        }
+
+        points.push(current.clone());
+    }
+
+    let scale = 1f64 / (1u64 << BITS) as f64;
+    let mut scramble_rng = Pcg64Mcg::from_seed(scramble_seed);
+    let zero_to_one = Uniform::new(0f64, 1f64);
+    let shifts: Vec<f64> = (0..dimensions)
+        .map(|_| scramble_rng.sample(zero_to_one))
+        .collect();
+
+    let span = bounds.upper - bounds.lower;
+
+    points
+        .into_iter()
+        .map(|point| {
+            point
+                .into_iter()
+                .zip(shifts.iter())
+                .map(|(value, shift)| {
+                    let unit_interval_value =
+                        ((value as f64 * scale) + shift).fract();
+
+                    bounds.lower + (unit_interval_value * span)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Builds the per-dimension direction number tables (each entry `i` holds
+/// `v_i` left-shifted so it already sits in the high `BITS` bits, matching
+/// the Gray-code update used in [`scrambled_sobol_sequence`]).
+fn build_direction_numbers(dimensions: usize) -> Vec<Vec<u32>> {
+    let mut tables: Vec<Vec<u32>> = (0..dimensions)
+        .map(direction_numbers_for_dimension)
+        .collect();
+
+    // Defensive pass: `initial_direction_number` isn't guaranteed collision-free
+    // for every (dimension, polynomial) combination - a degenerate table ends up
+    // bit-for-bit identical to an earlier dimension's, which makes those two
+    // axes perfectly correlated (see the regression test below, and the review
+    // that caught the previous formula collapsing dimensions 1 and 3 onto
+    // dimension 0's van der Corput sequence). Detect and break any duplicate
+    // regardless of how it arose, instead of trusting the derivation alone.
+    for dimension in 1..tables.len() {
+        // `salt` bounds how many bit-flip attempts we make before giving up:
+        // once `salt >= BITS`, every index satisfies `i as u32 >= salt` is
+        // false, so the flip loop below becomes a no-op and `salt += 1` alone
+        // would spin forever on a collision it can no longer perturb away.
+        let mut salt: u32 = 1;
+        while tables[..dimension].contains(&tables[dimension]) {
+            assert!(
+                salt < BITS,
+                "BUG: could not break duplicate direction-number table for \
+                 dimension {dimension} within {BITS} salt attempts - \
+                 initial_direction_number needs a better perturbation"
+            );
+
+            for (i, direction) in tables[dimension].iter_mut().enumerate() {
+                if i as u32 >= salt {
+                    *direction ^= 1u32 << (BITS as usize - 1 - i);
+                }
+            }
+            salt += 1;
+        }
+    }
+
+    tables
+}
+
+fn direction_numbers_for_dimension(dimension: usize) -> Vec<u32> {
+    let mut directions = vec![0u32; BITS as usize];
+
+    if dimension == 0 {
+        // Dimension 1 is the trivial van der Corput sequence: v_i = 1 << (BITS - i).
+        for (i, direction) in directions.iter_mut().enumerate() {
+            *direction = 1u32 << (BITS as usize - 1 - i);
+        }
+        return directions;
+    }
+
+    let polynomial = PRIMITIVE_POLYNOMIALS
+        [(dimension - 1) % PRIMITIVE_POLYNOMIALS.len()];
+    let degree = polynomial_degree(polynomial);
+
+    // Initial direction numbers `m_1..m_degree` must each be odd and
+    // `m_i < 2^i`. We don't reproduce the (much larger) validated Joe & Kuo
+    // initial-number tables here; instead we deterministically derive a valid
+    // seed per dimension, which keeps the construction correct and gives
+    // every dimension a distinct sequence (see `initial_direction_number`,
+    // plus the duplicate-table fallback in `build_direction_numbers`) - but
+    // without the proven low-discrepancy coverage the real tables give. See
+    // the module-level note on `scrambled_sobol_sequence` for what guarantee
+    // this construction actually provides.
+    let mut m: Vec<u32> = (1..=degree)
+        .map(|i| initial_direction_number(dimension, i))
+        .collect();
+
+    for i in 0..BITS {
+        if i < degree {
+            directions[i as usize] = m[i as usize] << (BITS - 1 - i);
+        } else {
+            // Recurrence: v_i = a_1*v_{i-1} ^ ... ^ a_{degree-1}*v_{i-degree+1} ^ v_{i-degree} ^ (v_{i-degree} >> degree)
+            // Since `v_i = m_i << (BITS - 1 - i)`, on the unshifted `m` values
+            // this reads: m_i = 2*a_1*m_{i-1} ^ ... ^ 2^degree*m_{i-degree} ^ m_{i-degree}.
+            let base = m[(i - degree) as usize];
+            let mut value = (base << degree) ^ base;
+
+            for j in 1..degree {
+                let polynomial_bit = (polynomial >> (degree - j)) & 1;
+                if polynomial_bit == 1 {
+                    value ^= m[(i - j) as usize] << j;
+                }
+            }
+
+            m.push(value);
+            directions[i as usize] = value << (BITS - 1 - i);
+        }
+    }
+
+    directions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Regression test for the bug caught in review: the original
+    /// `(2*dimension + i) % 2^i | 1` initial-number formula forced several
+    /// dimensions' direction-number tables to collapse into an exact copy of
+    /// dimension 0's van der Corput sequence, making those axes perfectly
+    /// correlated for the entire run - strictly worse coverage than the
+    /// uniform sampling Sobol was meant to replace.
+    #[test]
+    fn direction_number_tables_are_pairwise_distinct() {
+        let tables = build_direction_numbers(16);
+
+        for (first_index, first_table) in tables.iter().enumerate() {
+            for (second_index, second_table) in tables.iter().enumerate() {
+                if first_index != second_index {
+                    assert_ne!(
+                        first_table, second_table,
+                        "dimensions {} and {} produced identical direction-number tables",
+                        first_index, second_index
+                    );
+                }
+            }
+        }
+    }
+
+    /// With distinct direction-number tables, the first two axes of a
+    /// scrambled Sobol sequence should not sit on an (almost) perfectly
+    /// correlated diagonal line.
+    #[test]
+    fn scrambled_sequence_axes_are_not_perfectly_correlated() {
+        let points = scrambled_sobol_sequence(
+            4,
+            512,
+            Bounds::new(-5f64, 5f64),
+            [0u8; 16],
+        );
+
+        let first_axis: Vec<f64> = points.iter().map(|point| point[0]).collect();
+        let second_axis: Vec<f64> = points.iter().map(|point| point[1]).collect();
+
+        assert!(
+            pearson_correlation(&first_axis, &second_axis).abs() < 0.9,
+            "first and second Sobol axes are nearly perfectly correlated"
+        );
+    }
+
+    fn pearson_correlation(a: &[f64], b: &[f64]) -> f64 {
+        let n = a.len() as f64;
+        let mean_a = a.iter().sum::<f64>() / n;
+        let mean_b = b.iter().sum::<f64>() / n;
+
+        let mut covariance = 0f64;
+        let mut variance_a = 0f64;
+        let mut variance_b = 0f64;
+
+        for (value_a, value_b) in a.iter().zip(b.iter()) {
+            let delta_a = value_a - mean_a;
+            let delta_b = value_b - mean_b;
+
+            covariance += delta_a * delta_b;
+            variance_a += delta_a * delta_a;
+            variance_b += delta_b * delta_b;
+        }
+
+        covariance / (variance_a.sqrt() * variance_b.sqrt())
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod common;
+pub mod firefly;
+pub mod optimizer;
+pub mod pso;
+pub mod tune;
@@ -0,0 +1,22 @@
+use miette::Result;
+
+use super::common::Minimum;
+use crate::core::problem::BBOBProblem;
+
+/// Common interface for swarm/population-based optimization algorithms
+/// (firefly, PSO, ...), letting callers like `cmd_run_firefly_optimization`
+/// benchmark different algorithms over `ALL_BBOB_FUNCTION_NAMES` with
+/// identical harness code.
+pub trait Optimizer {
+    /// The algorithm's tunable parameters (e.g. `FireflyOptions`). `Clone` so
+    /// harnesses like `run_bbob_benchmark` can reuse one caller-built options
+    /// value across every problem in a run.
+    type Options: Default + Clone;
+
+    /// Runs the algorithm to completion on `problem`, returning the best
+    /// minimum found. `None` options fall back to `Options::default()`.
+    fn optimize(
+        problem: BBOBProblem,
+        options: Option<Self::Options>,
+    ) -> Result<Minimum>;
+}
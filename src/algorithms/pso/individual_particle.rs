@@ -0,0 +1,108 @@
+use super::options::PSOOptions;
+use crate::algorithms::common::UniformRNG;
+use crate::core::problem::BBOBProblem;
+
+/// A single particle in the swarm, carrying its position, velocity, a cached
+/// objective value and its personal best, plus a dedicated (pre-seeded) RNG
+/// for the `r1`/`r2` coefficients, so runs stay deterministic even when the
+/// swarm is processed across multiple threads.
+///
+/// `BBOBProblem::evaluate` only reads the problem definition, so particles
+/// can be updated concurrently against a single, shared `&BBOBProblem` (the
+/// same reasoning `firefly::individual_firefly::Firefly` relies on).
+#[derive(Clone)]
+pub struct Particle {
+    /// RNG used for the velocity update's `r1`/`r2` coefficients.
+    /// Sampled from `Bounds::new(0f64, 1f64)`.
+    coefficient_rng: UniformRNG,
+
+    pub position: Vec<f64>,
+    pub velocity: Vec<f64>,
+
+    pub objective_function_value: f64,
+
+    pub personal_best_position: Vec<f64>,
+    pub personal_best_value: f64,
+}
+
+impl Particle {
+    pub fn new(
+        coefficient_rng: UniformRNG,
+        position: Vec<f64>,
+        velocity: Vec<f64>,
+        problem: &BBOBProblem,
+    ) -> Self {
+        let objective_function_value = problem.evaluate(&position);
+
+        Self {
+            coefficient_rng,
+            personal_best_position: position.clone(),
+            personal_best_value: objective_function_value,
+            position,
+            velocity,
+            objective_function_value,
+        }
+    }
+
+    /// Performs a single PSO velocity/position update (see [1]):
+    /// `v = w*v + c1*r1*(pbest - x) + c2*r2*(gbest - x)`, `x += v`, with
+    /// velocity clamping and bounds reflection (a particle that would step
+    /// outside the problem's bounds is reflected back in, with its velocity
+    /// along that dimension flipped).
+    ///
+    /// [1] https://doi.org/10.1109/ICNN.1995.488968
+    pub fn update(
+        &mut self,
+        global_best_position: &[f64],
+        problem: &BBOBProblem,
+        options: &PSOOptions,
+    ) {
+        let bounds = problem.bounds();
+
+        for dimension_index in 0..self.position.len() {
+            let r1 = self.coefficient_rng.sample();
+            let r2 = self.coefficient_rng.sample();
+
+            let cognitive_pull = options.cognitive_coefficient
+                * r1
+                * (self.personal_best_position[dimension_index]
+                    - self.position[dimension_index]);
+
+            let social_pull = options.social_coefficient
+                * r2
+                * (global_best_position[dimension_index]
+                    - self.position[dimension_index]);
+
+            let new_velocity = options.inertia_weight
+                * self.velocity[dimension_index]
+                + cognitive_pull
+                + social_pull;
+
+            self.velocity[dimension_index] =
+                new_velocity.clamp(-options.velocity_clamp, options.velocity_clamp);
+
+            let mut new_position =
+                self.position[dimension_index] + self.velocity[dimension_index];
+
+            // Bounds reflection: bounce back in and flip the velocity's sign
+            // along this dimension, rather than clamping and killing momentum.
+            if new_position < bounds.lower {
+                new_position = bounds.lower + (bounds.lower - new_position);
+                self.velocity[dimension_index] = -self.velocity[dimension_index];
+            } else if new_position > bounds.upper {
+                new_position = bounds.upper - (new_position - bounds.upper);
+                self.velocity[dimension_index] = -self.velocity[dimension_index];
+            }
+
+            self.position[dimension_index] =
+                new_position.clamp(bounds.lower, bounds.upper);
+        }
+
+        self.objective_function_value = problem.evaluate(&self.position);
+
+        if self.objective_function_value < self.personal_best_value {
+            self.personal_best_value = self.objective_function_value;
+            self.personal_best_position = self.position.clone();
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use individual_particle::Particle;
+use miette::Result;
+use options::PSOOptions;
+use rand::distributions::Uniform;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+
+use super::common::{
+    run_until_stuck,
+    IterationResult,
+    Minimum,
+    PointAndValue,
+    SwarmOptimization,
+    UniformRNG,
+};
+use super::optimizer::Optimizer;
+use crate::core::problem::{BBOBProblem, Bounds};
+
+mod individual_particle;
+mod options;
+
+
+/// Entire particle swarm.
+pub struct PSOSwarm<'problem, 'options> {
+    problem: BBOBProblem<'problem>,
+
+    best_solution: Option<PointAndValue>,
+
+    options: &'options PSOOptions,
+
+    particles: Vec<Particle>,
+}
+
+impl<'problem, 'options> PSOSwarm<'problem, 'options> {
+    pub fn initialize(
+        problem: BBOBProblem<'problem>,
+        options: &'options PSOOptions,
+    ) -> Self {
+        let input_dimensions = problem.input_dimensions;
+
+        // Generates uniformly-distributed f64 values in the problem's range (-5 to 5).
+        let mut in_bounds_uniform_generator = UniformRNG::new(
+            problem.bounds(),
+            options.in_bounds_random_generator_seed,
+        );
+
+        // Temporary reseeding RNG - generates u8 seeds for individual particles' RNGs.
+        // This way we can preserve determinism, even when multi-threading.
+        // (Reuses the same deterministic per-member seeding scheme as `FireflySwarm::initialize`.)
+        let u8_uniform_distribution = Uniform::new_inclusive(u8::MIN, u8::MAX);
+        let mut particle_seed_generator =
+            Pcg64Mcg::from_seed(options.particle_seed_generator_seed);
+
+        let particles: Vec<Particle> = (0..options.swarm_size)
+            .map(|_| {
+                let further_generation_seed: [u8; 16] = (0..16)
+                    .map(|_| {
+                        particle_seed_generator.sample(u8_uniform_distribution)
+                    })
+                    .collect::<Vec<u8>>()
+                    .try_into()
+                    .expect("BUG: Iterator did not generate 16 u8?!?!");
+
+                let initial_position: Vec<f64> = in_bounds_uniform_generator
+                    .sample_multiple(input_dimensions);
+                let initial_velocity = vec![0f64; input_dimensions];
+
+                Particle::new(
+                    UniformRNG::new(
+                        Bounds::new(0f64, 1f64),
+                        further_generation_seed,
+                    ),
+                    initial_position,
+                    initial_velocity,
+                    &problem,
+                )
+            })
+            .collect();
+
+        let best_solution = particles
+            .iter()
+            .min_by(|first, second| {
+                first
+                    .objective_function_value
+                    .total_cmp(&second.objective_function_value)
+            })
+            .map(|particle| {
+                PointAndValue::new(
+                    particle.position.clone(),
+                    particle.objective_function_value,
+                )
+            });
+
+        Self {
+            problem,
+            best_solution,
+            options,
+            particles,
+        }
+    }
+
+    pub fn perform_iteration(&mut self) -> IterationResult {
+        assert_eq!(self.particles.len(), self.options.swarm_size);
+
+        let mut result = IterationResult::new(false);
+
+        let global_best_position = self
+            .best_solution
+            .as_ref()
+            .map(|point| point.position.clone())
+            .expect("BUG: swarm has no global best after initialization?!");
+
+        for particle in self.particles.iter_mut() {
+            particle.update(
+                &global_best_position,
+                &self.problem,
+                self.options,
+            );
+
+            if particle.objective_function_value
+                < self.best_solution.as_ref().unwrap().value
+            {
+                self.best_solution = Some(PointAndValue::new(
+                    particle.position.clone(),
+                    particle.objective_function_value,
+                ));
+
+                result.new_global_minimum = true;
+            }
+        }
+
+        result
+    }
+}
+
+impl SwarmOptimization for PSOSwarm<'_, '_> {
+    #[inline]
+    fn step(&mut self, _current_iteration: usize) -> IterationResult {
+        self.perform_iteration()
+    }
+
+    #[inline]
+    fn into_best_solution(self) -> Option<PointAndValue> {
+        self.best_solution
+    }
+}
+
+
+pub fn perform_particle_swarm_optimization(
+    problem: BBOBProblem,
+    options: Option<PSOOptions>,
+) -> Result<Minimum> {
+    let options = options.unwrap_or_default();
+    let swarm = PSOSwarm::initialize(problem, &options);
+
+    run_until_stuck(
+        swarm,
+        options.maximum_iterations,
+        options.stuck_run_iterations_count,
+    )
+}
+
+
+/// [`Optimizer`] adapter for particle swarm optimization, so it can be
+/// benchmarked through the same harness as other algorithms (e.g. `firefly`).
+pub struct PSOOptimizer;
+
+impl Optimizer for PSOOptimizer {
+    type Options = PSOOptions;
+
+    fn optimize(
+        problem: BBOBProblem,
+        options: Option<PSOOptions>,
+    ) -> Result<Minimum> {
+        perform_particle_swarm_optimization(problem, options)
+    }
+}
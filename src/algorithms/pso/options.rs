@@ -0,0 +1,62 @@
+/// References:
+///  [1] https://doi.org/10.1109/ICNN.1995.488968
+#[derive(Debug, Clone)]
+pub struct PSOOptions {
+    /// Amount of particles in the swarm. Kept constant throughout the run.
+    pub swarm_size: usize,
+
+    /// A 16-byte random generator seed for the swarm initialization.
+    pub in_bounds_random_generator_seed: [u8; 16],
+
+    /// A 16-byte random generator seed used to re-seed each particle's
+    /// own `r1`/`r2` generator (see `Particle::coefficient_rng`), the
+    /// same way `FireflySwarm::initialize` seeds individual fireflies -
+    /// this keeps runs deterministic even when particles are updated
+    /// across multiple threads.
+    pub particle_seed_generator_seed: [u8; 16],
+
+    /// Maximum number of iterations to perform.
+    pub maximum_iterations: usize,
+
+    /// How many consequent iterations of non-improvement to tolerate before
+    /// aborting the run and returning the current minimum.
+    pub stuck_run_iterations_count: usize,
+
+    /// Inertia weight (`w` in [1]), controlling how much of the previous
+    /// velocity carries over. Generally in range `[0.4, 0.9]`.
+    pub inertia_weight: f64,
+
+    /// Cognitive coefficient (`c1` in [1]), pulling a particle towards its
+    /// own personal best.
+    pub cognitive_coefficient: f64,
+
+    /// Social coefficient (`c2` in [1]), pulling a particle towards the
+    /// swarm's global best.
+    pub social_coefficient: f64,
+
+    /// Clamps each velocity component to `[-velocity_clamp, velocity_clamp]`,
+    /// preventing particles from overshooting the search space.
+    pub velocity_clamp: f64,
+}
+
+impl Default for PSOOptions {
+    fn default() -> Self {
+        Self {
+            swarm_size: 150,
+            in_bounds_random_generator_seed: [
+                199, 248, 17, 170, 248, 248, 15, 82, 75, 207, 232, 76, 38, 70,
+                37, 111,
+            ],
+            particle_seed_generator_seed: [
+                160, 142, 67, 136, 64, 230, 125, 10, 243, 246, 140, 229, 12,
+                95, 173, 104,
+            ],
+            maximum_iterations: 5000,
+            stuck_run_iterations_count: 500,
+            inertia_weight: 0.729,
+            cognitive_coefficient: 1.49445,
+            social_coefficient: 1.49445,
+            velocity_clamp: 2f64,
+        }
+    }
+}
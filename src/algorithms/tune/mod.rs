@@ -0,0 +1,190 @@
+use miette::{miette, Result};
+use options::{SearchRange, TuneOptions};
+use rand::distributions::Uniform;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
+
+use super::firefly::{perform_firefly_swarm_optimization, FireflyOptions};
+use crate::core::suite::BBOBSuite;
+
+pub mod options;
+
+/// Number of `FireflyOptions` hyperparameters being searched: `swarm_size`,
+/// `light_absorption_coefficient`, `attractiveness_coefficient`,
+/// `movement_jitter_coefficient` and `maximum_iterations`, in that order.
+const HYPERPARAMETER_COUNT: usize = 5;
+
+fn search_ranges(options: &TuneOptions) -> [SearchRange; HYPERPARAMETER_COUNT] {
+    [
+        options.swarm_size_range,
+        options.light_absorption_coefficient_range,
+        options.attractiveness_coefficient_range,
+        options.movement_jitter_coefficient_range,
+        options.maximum_iterations_range,
+    ]
+}
+
+fn candidate_to_firefly_options(
+    candidate: &[f64; HYPERPARAMETER_COUNT],
+    inner_seed: [u8; 16],
+) -> FireflyOptions {
+    let mut firefly_options = FireflyOptions::default();
+
+    firefly_options.swarm_size = candidate[0].round().max(1f64) as usize;
+    firefly_options.light_absorption_coefficient = candidate[1];
+    firefly_options.attractiveness_coefficient = candidate[2];
+    firefly_options.movement_jitter_coefficient = candidate[3];
+    firefly_options.maximum_iterations = candidate[4].round().max(1f64) as usize;
+
+    // Fixed across every candidate, so the comparison only reflects the
+    // hyperparameters actually being tuned.
+    firefly_options.in_bounds_random_generator_seed = inner_seed;
+    firefly_options.firefly_seed_generator_seed = inner_seed;
+
+    firefly_options
+}
+
+/// Runs the candidate configuration over `options.target_functions` and
+/// returns the mean of the minima found - the candidate's fitness (lower is better).
+fn evaluate_candidate(
+    candidate: &[f64; HYPERPARAMETER_COUNT],
+    options: &TuneOptions,
+    suite: &mut BBOBSuite,
+) -> Result<f64> {
+    let firefly_options =
+        candidate_to_firefly_options(candidate, options.inner_seed);
+
+    let mut total_minimum_value = 0f64;
+
+    for function_name in &options.target_functions {
+        let problem = suite.problem(*function_name, None)?;
+
+        let minimum = perform_firefly_swarm_optimization(
+            problem,
+            Some(firefly_options.clone()),
+        )?;
+
+        total_minimum_value += minimum.value;
+    }
+
+    Ok(total_minimum_value / options.target_functions.len() as f64)
+}
+
+struct OuterParticle {
+    position: [f64; HYPERPARAMETER_COUNT],
+    velocity: [f64; HYPERPARAMETER_COUNT],
+
+    fitness: f64,
+
+    personal_best_position: [f64; HYPERPARAMETER_COUNT],
+    personal_best_fitness: f64,
+}
+
+/// Searches `FireflyOptions`' continuous hyperparameter space (see
+/// `HYPERPARAMETER_COUNT`) for a configuration that minimizes the mean
+/// best value found on `options.target_functions`, modelling each candidate
+/// configuration as a particle in a small outer particle swarm (the same
+/// velocity-update rule as `pso::individual_particle::Particle::update`,
+/// applied here to hyperparameters instead of a BBOB problem's coordinates).
+///
+/// This automates what used to be the manual grid-search recorded in
+/// `FireflyOptions`' default-value comments.
+pub fn tune_firefly_options(options: &TuneOptions) -> Result<FireflyOptions> {
+    if options.target_functions.is_empty() {
+        return Err(miette!(
+            "Cannot tune FireflyOptions: no target_functions configured."
+        ));
+    }
+
+    let ranges = search_ranges(options);
+
+    // Built once and reused across every candidate evaluation below (an outer
+    // PSO run easily evaluates hundreds of candidates - `run_bbob_benchmark`
+    // sets the precedent of one suite per run, rather than one per problem).
+    let mut suite = BBOBSuite::new()?;
+
+    let mut rng = Pcg64Mcg::from_seed(options.outer_random_generator_seed);
+    let zero_to_one = Uniform::new(0f64, 1f64);
+
+    let mut particles: Vec<OuterParticle> = (0..options.outer_swarm_size)
+        .map(|_| {
+            let position: [f64; HYPERPARAMETER_COUNT] =
+                std::array::from_fn(|dimension| {
+                    let range = ranges[dimension];
+                    range.min + rng.sample(zero_to_one) * (range.max - range.min)
+                });
+
+            OuterParticle {
+                position,
+                velocity: [0f64; HYPERPARAMETER_COUNT],
+                fitness: f64::INFINITY,
+                personal_best_position: position,
+                personal_best_fitness: f64::INFINITY,
+            }
+        })
+        .collect();
+
+    for particle in particles.iter_mut() {
+        particle.fitness =
+            evaluate_candidate(&particle.position, options, &mut suite)?;
+        particle.personal_best_fitness = particle.fitness;
+    }
+
+    let mut global_best_position = particles
+        .iter()
+        .min_by(|first, second| {
+            first.fitness.total_cmp(&second.fitness)
+        })
+        .map(|particle| particle.position)
+        .expect("BUG: outer swarm is empty?!");
+    let mut global_best_fitness = particles
+        .iter()
+        .map(|particle| particle.fitness)
+        .fold(f64::INFINITY, f64::min);
+
+    for _ in 0..options.outer_maximum_iterations {
+        for particle in particles.iter_mut() {
+            for dimension in 0..HYPERPARAMETER_COUNT {
+                let range = ranges[dimension];
+                let r1 = rng.sample(zero_to_one);
+                let r2 = rng.sample(zero_to_one);
+
+                let cognitive_pull = options.cognitive_coefficient
+                    * r1
+                    * (particle.personal_best_position[dimension]
+                        - particle.position[dimension]);
+                let social_pull = options.social_coefficient
+                    * r2
+                    * (global_best_position[dimension]
+                        - particle.position[dimension]);
+
+                particle.velocity[dimension] = options.inertia_weight
+                    * particle.velocity[dimension]
+                    + cognitive_pull
+                    + social_pull;
+
+                particle.position[dimension] = (particle.position[dimension]
+                    + particle.velocity[dimension])
+                    .clamp(range.min, range.max);
+            }
+
+            particle.fitness =
+                evaluate_candidate(&particle.position, options, &mut suite)?;
+
+            if particle.fitness < particle.personal_best_fitness {
+                particle.personal_best_fitness = particle.fitness;
+                particle.personal_best_position = particle.position;
+            }
+
+            if particle.fitness < global_best_fitness {
+                global_best_fitness = particle.fitness;
+                global_best_position = particle.position;
+            }
+        }
+    }
+
+    Ok(candidate_to_firefly_options(
+        &global_best_position,
+        options.inner_seed,
+    ))
+}
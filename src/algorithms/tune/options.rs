@@ -0,0 +1,82 @@
+use crate::core::names::BBOBFunctionName;
+
+/// Inclusive `[min, max]` search range for a single hyperparameter.
+#[derive(Debug, Clone, Copy)]
+pub struct SearchRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl SearchRange {
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+}
+
+/// Configures `tune_firefly_options`: which `FireflyOptions` hyperparameters
+/// to search, over which BBOB functions, and with which outer-PSO settings.
+#[derive(Debug, Clone)]
+pub struct TuneOptions {
+    /// Amount of candidate configurations (outer-PSO particles) searched in parallel.
+    pub outer_swarm_size: usize,
+
+    /// Maximum number of outer-PSO iterations.
+    pub outer_maximum_iterations: usize,
+
+    /// A 16-byte random generator seed for the outer swarm's initialization and
+    /// velocity-update coefficients.
+    pub outer_random_generator_seed: [u8; 16],
+
+    /// Subset of `ALL_BBOB_FUNCTION_NAMES` a candidate configuration is scored
+    /// against. The fitness of a candidate is the mean of the minima found on
+    /// each of these functions.
+    pub target_functions: Vec<BBOBFunctionName>,
+
+    /// Fixed inner seed, reused for every candidate's firefly run so that
+    /// candidates are compared on equal footing (only the hyperparameters
+    /// being tuned differ between runs).
+    pub inner_seed: [u8; 16],
+
+    pub swarm_size_range: SearchRange,
+    pub light_absorption_coefficient_range: SearchRange,
+    pub attractiveness_coefficient_range: SearchRange,
+    pub movement_jitter_coefficient_range: SearchRange,
+    pub maximum_iterations_range: SearchRange,
+
+    /// Outer-PSO inertia weight.
+    pub inertia_weight: f64,
+    /// Outer-PSO cognitive coefficient.
+    pub cognitive_coefficient: f64,
+    /// Outer-PSO social coefficient.
+    pub social_coefficient: f64,
+}
+
+impl Default for TuneOptions {
+    fn default() -> Self {
+        Self {
+            outer_swarm_size: 20,
+            outer_maximum_iterations: 30,
+            outer_random_generator_seed: [
+                12, 55, 201, 9, 88, 233, 9, 150, 61, 47, 210, 3, 128, 199, 52,
+                74,
+            ],
+            target_functions: Vec::new(),
+            inner_seed: [
+                199, 248, 17, 170, 248, 248, 15, 82, 75, 207, 232, 76, 38, 70,
+                37, 111,
+            ],
+            swarm_size_range: SearchRange::new(15f64, 200f64),
+            light_absorption_coefficient_range: SearchRange::new(
+                0.001f64, 0.5f64,
+            ),
+            attractiveness_coefficient_range: SearchRange::new(0.1f64, 1f64),
+            movement_jitter_coefficient_range: SearchRange::new(
+                0.001f64, 0.5f64,
+            ),
+            maximum_iterations_range: SearchRange::new(200f64, 5000f64),
+            inertia_weight: 0.729,
+            cognitive_coefficient: 1.49445,
+            social_coefficient: 1.49445,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod firefly_optimization;
+pub mod pso_optimization;
+pub mod run_optimization;
+pub mod tune_firefly;
@@ -0,0 +1,8 @@
+use miette::Result;
+
+use crate::algorithms::pso::PSOOptimizer;
+use crate::commands::run_optimization::run_bbob_benchmark;
+
+pub fn cmd_run_particle_swarm_optimization() -> Result<()> {
+    run_bbob_benchmark::<PSOOptimizer>("particle swarm optimization", None)
+}
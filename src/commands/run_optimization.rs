@@ -0,0 +1,51 @@
+use std::time::Instant;
+
+use miette::Result;
+
+use crate::algorithms::optimizer::Optimizer;
+use crate::core::names::ALL_BBOB_FUNCTION_NAMES;
+use crate::core::suite::BBOBSuite;
+
+/// Runs `O` over every `ALL_BBOB_FUNCTION_NAMES` problem, printing a line per
+/// finished problem. Shared by `cmd_run_firefly_optimization` and
+/// `cmd_run_particle_swarm_optimization` so both algorithms are benchmarked
+/// with identical harness code.
+///
+/// `options` is cloned into every `O::optimize` call (`None` falls back to
+/// `O::Options::default()`), so callers that want to toggle something like
+/// `FireflyOptions::progress_reporting_enabled` can build it once up front
+/// instead of only being reachable by constructing custom options elsewhere.
+pub fn run_bbob_benchmark<O: Optimizer>(
+    algorithm_name: &str,
+    options: Option<O::Options>,
+) -> Result<()> {
+    // Initialize coco / the BBOB suite.
+    let mut suite = BBOBSuite::new()?;
+
+    println!("-- Running {} --", algorithm_name);
+    let start_time = Instant::now();
+
+    for problem_name in ALL_BBOB_FUNCTION_NAMES {
+        let problem = suite.problem(problem_name, None)?;
+        let problem_start_time = Instant::now();
+
+        let minimum = O::optimize(problem, options.clone())?;
+
+        let problem_delta_time = problem_start_time.elapsed().as_secs_f64();
+        println!(
+            "[{:02}/{:02}|{}] {}Minimum: {:.6}    ({:.4} seconds)",
+            problem_name.function_index(),
+            ALL_BBOB_FUNCTION_NAMES.len(),
+            problem_name.function_name(),
+            " ".repeat(32 - problem_name.function_name().len()),
+            minimum.value,
+            problem_delta_time
+        );
+    }
+
+    let delta_time = start_time.elapsed().as_secs_f64();
+
+    println!("-- Finished in {:.4} seconds --", delta_time);
+
+    Ok(())
+}
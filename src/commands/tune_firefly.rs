@@ -0,0 +1,30 @@
+use miette::Result;
+
+use crate::algorithms::tune::options::TuneOptions;
+use crate::algorithms::tune::tune_firefly_options;
+use crate::core::names::ALL_BBOB_FUNCTION_NAMES;
+
+pub fn cmd_run_firefly_tuning() -> Result<()> {
+    let tune_options = TuneOptions {
+        // Tuning against every single BBOB function would be prohibitively slow -
+        // a handful spread across the suite is enough to get a configuration that
+        // generalizes reasonably well.
+        target_functions: ALL_BBOB_FUNCTION_NAMES
+            .iter()
+            .step_by(4)
+            .copied()
+            .collect(),
+        ..TuneOptions::default()
+    };
+
+    println!(
+        "Tuning FireflyOptions against {} BBOB functions...",
+        tune_options.target_functions.len()
+    );
+
+    let best_options = tune_firefly_options(&tune_options)?;
+
+    println!("Best FireflyOptions found:\n{:#?}", best_options);
+
+    Ok(())
+}